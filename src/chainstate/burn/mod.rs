@@ -971,4 +971,23 @@ mod tests {
             ]
         );
     }
+
+    /// Smoke test that the deterministic op fixtures in
+    /// `operations::test_helpers` are visible and usable from outside the
+    /// operations module, as downstream crates would via the `testing`
+    /// feature.
+    #[test]
+    fn operations_test_helpers_visible() {
+        use chainstate::burn::operations::test_helpers;
+
+        let header = test_helpers::burnchain_block_header_at(100, 1);
+        assert_eq!(header.block_height, 100);
+        assert_eq!(header, test_helpers::burnchain_block_header_at(100, 1));
+
+        let txid = test_helpers::txid_from_seed(1);
+        assert_eq!(txid, test_helpers::txid_from_seed(1));
+
+        let mut rng = test_helpers::seeded_rng(1);
+        assert_eq!(test_helpers::random_bytes(&mut rng, 32).len(), 32);
+    }
 }
@@ -395,3 +395,84 @@ pub fn parse_u32_from_be(bytes: &[u8]) -> Option<u32> {
 pub fn parse_u16_from_be(bytes: &[u8]) -> Option<u16> {
     bytes.try_into().ok().map(u16::from_be_bytes)
 }
+
+/// Deterministic helpers for constructing synthetic burnchain operations in
+/// tests.  Every helper is driven by an explicit seed: the same seed always
+/// produces the same bytes, so fixtures built from them remain stable across
+/// runs and machines.  Also available to downstream crates (e.g. stacks-node
+/// integration tests) via the `testing` feature.
+#[cfg(any(test, feature = "testing"))]
+pub mod test_helpers {
+    use rand::rngs::StdRng;
+    use rand::RngCore;
+    use rand::SeedableRng;
+
+    use burnchains::BurnchainBlockHeader;
+    use burnchains::BurnchainHeaderHash;
+    use burnchains::Txid;
+
+    /// Make a deterministic RNG from the given seed.
+    pub fn seeded_rng(seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
+
+    /// Draw `len` bytes from the given RNG.
+    pub fn random_bytes(rng: &mut StdRng, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; len];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Make a deterministic txid from the given seed.
+    pub fn txid_from_seed(seed: u64) -> Txid {
+        let mut rng = seeded_rng(seed);
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        Txid(bytes)
+    }
+
+    /// Make a synthetic burnchain block header at the given height, whose
+    /// block hash is derived deterministically from `hash_seed`.  The parent
+    /// block hash is derived from `hash_seed + 1` so that consecutive seeds
+    /// chain together.
+    pub fn burnchain_block_header_at(height: u64, hash_seed: u64) -> BurnchainBlockHeader {
+        let mut block_hash = [0u8; 32];
+        seeded_rng(hash_seed).fill_bytes(&mut block_hash);
+        let mut parent_block_hash = [0u8; 32];
+        seeded_rng(hash_seed.wrapping_add(1)).fill_bytes(&mut parent_block_hash);
+
+        BurnchainBlockHeader {
+            block_height: height,
+            block_hash: BurnchainHeaderHash(block_hash),
+            parent_block_hash: BurnchainHeaderHash(parent_block_hash),
+            num_txs: 1,
+            timestamp: height,
+        }
+    }
+
+    /// Make a synthetic burnchain block header at height 0 with seed 0.
+    pub fn burnchain_block_header() -> BurnchainBlockHeader {
+        burnchain_block_header_at(0, 0)
+    }
+
+    #[test]
+    fn helpers_are_deterministic() {
+        let mut rng_1 = seeded_rng(0x8badf00d);
+        let mut rng_2 = seeded_rng(0x8badf00d);
+        assert_eq!(random_bytes(&mut rng_1, 64), random_bytes(&mut rng_2, 64));
+
+        assert_eq!(txid_from_seed(123), txid_from_seed(123));
+        assert!(txid_from_seed(123) != txid_from_seed(124));
+
+        let header_1 = burnchain_block_header_at(100, 7);
+        let header_2 = burnchain_block_header_at(100, 7);
+        assert_eq!(header_1, header_2);
+        assert!(header_1.block_hash != burnchain_block_header_at(100, 8).block_hash);
+
+        // consecutive seeds chain: the parent of seed N is the hash of seed N+1
+        assert_eq!(
+            header_1.parent_block_hash,
+            burnchain_block_header_at(99, 8).block_hash
+        );
+    }
+}
@@ -655,7 +655,7 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
                     }
                 };
 
-                println!("{}", eval_result);
+                println!("{}", eval_result.pretty_print());
             }
         }
         "eval_raw" => {
@@ -687,7 +687,7 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
                     let result = vm_env.get_exec_environment(None).eval_raw(&content);
                     match result {
                         Ok(x) => {
-                            println!("Program executed successfully! Output: \n{}", x);
+                            println!("Program executed successfully! Output: \n{}", x.pretty_print());
                         }
                         Err(error) => {
                             eprintln!("Program execution error: \n{}", error);
@@ -726,7 +726,7 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
 
             match result {
                 Ok(x) => {
-                    println!("Program executed successfully! Output: \n{}", x);
+                    println!("Program executed successfully! Output: \n{}", x.pretty_print());
                 }
                 Err(error) => {
                     eprintln!("Program execution error: \n{}", error);
@@ -759,7 +759,7 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
 
             match result {
                 Ok(x) => {
-                    println!("Program executed successfully! Output: \n{}", x);
+                    println!("Program executed successfully! Output: \n{}", x.pretty_print());
                 }
                 Err(error) => {
                     eprintln!("Program execution error: \n{}", error);
@@ -812,7 +812,7 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
 
             match result {
                 Ok(x) => {
-                    println!("Program executed successfully! Output: \n{}", x);
+                    println!("Program executed successfully! Output: \n{}", x.pretty_print());
                 }
                 Err(error) => {
                     eprintln!("Program execution error: \n{}", error);
@@ -963,7 +963,8 @@ pub fn invoke_command(invoked_by: &str, args: &[String]) {
                         if data.committed {
                             println!(
                                 "Transaction executed and committed. Returned: {}\n{:?}",
-                                data.data, events
+                                data.data.pretty_print(),
+                                events
                             );
                         } else {
                             println!("Aborted: {}", data.data);
@@ -228,6 +228,24 @@ impl RPCPeerInfoData {
             genesis_chainstate_hash: genesis_chainstate_hash.clone(),
         })
     }
+
+    /// Compute the canonical Stacks tip's index block hash from the reported
+    /// `stacks_tip_consensus_hash` and `stacks_tip`.  Callers correlating
+    /// block data against `/v2/info` need the tip's `StacksBlockId`; deriving
+    /// it here saves them the consensus-hash parsing boilerplate.
+    pub fn stacks_tip_block_id(&self) -> Result<StacksBlockId, net_error> {
+        let consensus_hash =
+            ConsensusHash::from_hex(&self.stacks_tip_consensus_hash).map_err(|_e| {
+                net_error::DeserializeError(format!(
+                    "Failed to parse stacks tip consensus hash '{}'",
+                    &self.stacks_tip_consensus_hash
+                ))
+            })?;
+        Ok(StacksBlockHeader::make_index_block_hash(
+            &consensus_hash,
+            &self.stacks_tip,
+        ))
+    }
 }
 
 impl RPCPoxInfoData {
@@ -3193,6 +3211,41 @@ mod test {
         assert!(check_result(&req, &resp, &mut peer_1, &mut peer_2));
     }
 
+    #[test]
+    fn test_peer_info_stacks_tip_block_id() {
+        let consensus_hash = ConsensusHash([0x01; 20]);
+        let stacks_tip = BlockHeaderHash([0x02; 32]);
+        let mut peer_info = RPCPeerInfoData {
+            peer_version: 0,
+            pox_consensus: ConsensusHash([0x00; 20]),
+            burn_block_height: 0,
+            stable_pox_consensus: ConsensusHash([0x00; 20]),
+            stable_burn_block_height: 0,
+            server_version: "test".to_string(),
+            network_id: 0,
+            parent_network_id: 0,
+            stacks_tip_height: 0,
+            stacks_tip: stacks_tip.clone(),
+            stacks_tip_consensus_hash: consensus_hash.to_hex(),
+            genesis_chainstate_hash: Sha256Sum::zero(),
+            unanchored_tip: StacksBlockId([0x00; 32]),
+            unanchored_seq: 0,
+            exit_at_block_height: None,
+        };
+
+        assert_eq!(
+            peer_info.stacks_tip_block_id().unwrap(),
+            StacksBlockHeader::make_index_block_hash(&consensus_hash, &stacks_tip)
+        );
+
+        // an unparseable consensus hash is a deserialization error, not a panic
+        peer_info.stacks_tip_consensus_hash = "not a consensus hash".to_string();
+        match peer_info.stacks_tip_block_id() {
+            Err(net_error::DeserializeError(_)) => {}
+            x => panic!("Expected DeserializeError, got {:?}", &x),
+        }
+    }
+
     #[test]
     #[ignore]
     fn test_rpc_getinfo() {
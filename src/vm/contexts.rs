@@ -102,6 +102,13 @@ pub struct EventBatch {
      and is responsible for committing/rolling-back transactions as they error or
      abort.
 */
+/// Callback invoked by the boolean and control-flow special forms after they
+/// produce a result, with the id of the decisive argument expression, the
+/// form's name (e.g. "or"), and the resulting value.  Only available in
+/// developer-mode builds: consensus builds carry no hook state at all.
+#[cfg(feature = "developer-mode")]
+pub type EvalTraceHook = Box<dyn FnMut(u64, &str, &Value)>;
+
 pub struct GlobalContext<'a> {
     asset_maps: Vec<AssetMap>,
     pub event_batches: Vec<EventBatch>,
@@ -109,6 +116,8 @@ pub struct GlobalContext<'a> {
     read_only: Vec<bool>,
     pub cost_track: LimitedCostTracker,
     pub mainnet: bool,
+    #[cfg(feature = "developer-mode")]
+    pub eval_trace: Option<EvalTraceHook>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -752,6 +761,23 @@ impl<'a, 'b> Environment<'a, 'b> {
         }
     }
 
+    /// Report a special form's result to the installed evaluation trace hook,
+    /// if any.  `expr_id` is the id of the argument expression that decided
+    /// the result (e.g. the short-circuit point of an `and`/`or`).  A no-op
+    /// unless built with developer-mode and a hook was installed.
+    pub fn trace_eval(&mut self, expr_id: u64, form_name: &str, value: &Value) {
+        #[cfg(feature = "developer-mode")]
+        {
+            if let Some(ref mut hook) = self.global_context.eval_trace {
+                hook(expr_id, form_name, value);
+            }
+        }
+        #[cfg(not(feature = "developer-mode"))]
+        {
+            let _ = (expr_id, form_name, value);
+        }
+    }
+
     pub fn nest_as_principal<'c>(&'c mut self, sender: Value) -> Environment<'c, 'b> {
         Environment::new(
             self.global_context,
@@ -1278,9 +1304,19 @@ impl<'a> GlobalContext<'a> {
             asset_maps: Vec::new(),
             event_batches: Vec::new(),
             mainnet,
+            #[cfg(feature = "developer-mode")]
+            eval_trace: None,
         }
     }
 
+    /// Install an evaluation trace hook.  The boolean special forms (`and`,
+    /// `or`, `not`) will report their results through it -- see
+    /// `Environment::trace_eval`.
+    #[cfg(feature = "developer-mode")]
+    pub fn set_eval_trace(&mut self, hook: EvalTraceHook) {
+        self.eval_trace = Some(hook);
+    }
+
     pub fn is_top_level(&self) -> bool {
         self.asset_maps.len() == 0
     }
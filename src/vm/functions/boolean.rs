@@ -43,10 +43,12 @@ pub fn special_or(
         let evaluated = eval(&arg, env, context)?;
         let result = type_force_bool(&evaluated)?;
         if result {
+            env.trace_eval(arg.id, "or", &Value::Bool(true));
             return Ok(Value::Bool(true));
         }
     }
 
+    env.trace_eval(args[args.len() - 1].id, "or", &Value::Bool(false));
     Ok(Value::Bool(false))
 }
 
@@ -63,10 +65,12 @@ pub fn special_and(
         let evaluated = eval(&arg, env, context)?;
         let result = type_force_bool(&evaluated)?;
         if !result {
+            env.trace_eval(arg.id, "and", &Value::Bool(false));
             return Ok(Value::Bool(false));
         }
     }
 
+    env.trace_eval(args[args.len() - 1].id, "and", &Value::Bool(true));
     Ok(Value::Bool(true))
 }
 
@@ -191,6 +191,17 @@ pub fn apply(
             CallableType::UserFunction(function) => function.apply(&evaluated_args, env),
             _ => panic!("Should be unreachable."),
         };
+        // surface `not` in the boolean evaluation trace alongside `and` and
+        // `or`, which report from their special-form bodies
+        #[cfg(feature = "developer-mode")]
+        {
+            if let (CallableType::NativeFunction("native_not", _, _), Ok(ref value)) =
+                (function, &resp)
+            {
+                let expr_id = args.first().map(|arg| arg.id).unwrap_or(0);
+                env.trace_eval(expr_id, "not", value);
+            }
+        }
         add_stack_trace(&mut resp, env);
         env.drop_memory(used_memory);
         env.call_stack.remove(&identifier, track_recursion)?;
@@ -369,7 +380,9 @@ pub fn execute(program: &str) -> Result<Option<Value>> {
 
 #[cfg(test)]
 mod test {
+    use std::cell::RefCell;
     use std::collections::HashMap;
+    use std::rc::Rc;
     use vm::callables::{DefineType, DefinedFunction};
     use vm::costs::LimitedCostTracker;
     use vm::database::MemoryBackingStore;
@@ -434,4 +447,83 @@ mod test {
         );
         assert_eq!(Ok(Value::Int(64)), eval(&content[0], &mut env, &context));
     }
+
+    /// Test utility that records the sequence of (expr id, form name, value)
+    /// entries reported through the evaluation trace hook.
+    #[cfg(feature = "developer-mode")]
+    #[derive(Clone)]
+    struct TraceCollector {
+        entries: Rc<RefCell<Vec<(u64, String, Value)>>>,
+    }
+
+    #[cfg(feature = "developer-mode")]
+    impl TraceCollector {
+        fn new() -> TraceCollector {
+            TraceCollector {
+                entries: Rc::new(RefCell::new(Vec::new())),
+            }
+        }
+
+        fn hook(&self) -> super::contexts::EvalTraceHook {
+            let entries = self.entries.clone();
+            Box::new(move |expr_id, form_name, value| {
+                entries
+                    .borrow_mut()
+                    .push((expr_id, form_name.to_string(), value.clone()));
+            })
+        }
+
+        fn forms_and_values(&self) -> Vec<(String, Value)> {
+            self.entries
+                .borrow()
+                .iter()
+                .map(|(_, form, value)| (form.clone(), value.clone()))
+                .collect()
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "developer-mode")]
+    fn test_eval_trace_short_circuit() {
+        use vm::ast;
+        use vm::eval_all;
+
+        let program = "(and (or false true) (not true) (or true true))";
+
+        let contract_id = QualifiedContractIdentifier::transient();
+        let mut contract_context = ContractContext::new(contract_id.clone());
+        let mut marf = MemoryBackingStore::new();
+        let mut global_context =
+            GlobalContext::new(false, marf.as_clarity_db(), LimitedCostTracker::new_free());
+
+        let collector = TraceCollector::new();
+        global_context.set_eval_trace(collector.hook());
+
+        let result = global_context
+            .execute(|g| {
+                let parsed = ast::build_ast(&contract_id, program, &mut ())?.expressions;
+                eval_all(&parsed, &mut contract_context, g)
+            })
+            .unwrap();
+        assert_eq!(result, Some(Value::Bool(false)));
+
+        // the first disjunction short-circuits at its `true`, `(not true)`
+        // produces false, and the `and` short-circuits there -- so the third
+        // disjunction never appears in the trace
+        assert_eq!(
+            collector.forms_and_values(),
+            vec![
+                ("or".to_string(), Value::Bool(true)),
+                ("not".to_string(), Value::Bool(false)),
+                ("and".to_string(), Value::Bool(false)),
+            ]
+        );
+
+        // each entry reports a real expression id from the AST
+        assert!(collector
+            .entries
+            .borrow()
+            .iter()
+            .all(|(expr_id, _, _)| *expr_id > 0));
+    }
 }
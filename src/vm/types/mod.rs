@@ -1063,6 +1063,69 @@ impl fmt::Display for Value {
     }
 }
 
+impl Value {
+    /// Render this value as indented Clarity syntax for human consumption
+    /// (CLI output, debug logs).  Scalars and flat composites render exactly
+    /// as `Display` does; tuples with multiple or nested members and lists
+    /// with nested members are broken across lines, one member per line.
+    pub fn pretty_print(&self) -> String {
+        let mut out = String::new();
+        self.pretty_print_at(&mut out, 0);
+        out
+    }
+
+    /// Would this value force its enclosing composite onto multiple lines?
+    fn is_composite(&self) -> bool {
+        match self {
+            Value::Tuple(_) => true,
+            Value::Sequence(SequenceData::List(_)) => true,
+            Value::Optional(OptionalData { data: Some(ref x) }) => x.is_composite(),
+            Value::Response(ref res_data) => res_data.data.is_composite(),
+            _ => false,
+        }
+    }
+
+    fn pretty_print_at(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth + 1);
+        match self {
+            Value::Tuple(ref data)
+                if data.data_map.len() > 1 || data.data_map.values().any(Value::is_composite) =>
+            {
+                out.push_str("(tuple");
+                for (name, value) in data.data_map.iter() {
+                    out.push_str(&format!("\n{}({} ", &indent, &**name));
+                    value.pretty_print_at(out, depth + 1);
+                    out.push_str(")");
+                }
+                out.push_str(")");
+            }
+            Value::Sequence(SequenceData::List(ref list_data))
+                if list_data.data.iter().any(Value::is_composite) =>
+            {
+                out.push_str("(");
+                for (ix, value) in list_data.data.iter().enumerate() {
+                    if ix > 0 {
+                        out.push_str(&format!("\n{}", &indent));
+                    }
+                    value.pretty_print_at(out, depth + 1);
+                }
+                out.push_str(")");
+            }
+            Value::Optional(OptionalData { data: Some(ref x) }) => {
+                out.push_str("(some ");
+                x.pretty_print_at(out, depth);
+                out.push_str(")");
+            }
+            Value::Response(ref res_data) => {
+                out.push_str(if res_data.committed { "(ok " } else { "(err " });
+                res_data.data.pretty_print_at(out, depth);
+                out.push_str(")");
+            }
+            _ => out.push_str(&format!("{}", self)),
+        }
+    }
+}
+
 impl PrincipalData {
     pub fn version(&self) -> u8 {
         match self {
@@ -1271,6 +1334,59 @@ impl fmt::Display for TupleData {
 #[cfg(test)]
 mod test {
     use super::*;
+
+    #[test]
+    fn test_pretty_print() {
+        // scalars and flat composites render exactly as Display does
+        for flat in [
+            Value::Int(-12),
+            Value::UInt(12),
+            Value::Bool(true),
+            Value::none(),
+            Value::some(Value::Int(1)).unwrap(),
+            Value::okay(Value::Int(1)).unwrap(),
+            Value::list_from(vec![Value::Int(1), Value::Int(2)]).unwrap(),
+            Value::from(
+                TupleData::from_data(vec![("a".into(), Value::Int(1))]).unwrap(),
+            ),
+        ]
+        .iter()
+        {
+            assert_eq!(flat.pretty_print(), format!("{}", flat));
+        }
+
+        // nested tuples get one member per line, indented by depth
+        let inner = Value::from(
+            TupleData::from_data(vec![
+                ("c".into(), Value::Int(2)),
+                ("d".into(), Value::Int(3)),
+            ])
+            .unwrap(),
+        );
+        let outer = Value::from(
+            TupleData::from_data(vec![("a".into(), Value::Int(1)), ("b".into(), inner)]).unwrap(),
+        );
+        assert_eq!(
+            outer.pretty_print(),
+            "(tuple\n  (a 1)\n  (b (tuple\n    (c 2)\n    (d 3))))"
+        );
+
+        // lists of composites break across lines; ok/some wrap inline
+        let list = Value::list_from(vec![
+            Value::from(TupleData::from_data(vec![("a".into(), Value::Int(1))]).unwrap()),
+            Value::from(TupleData::from_data(vec![("a".into(), Value::Int(2))]).unwrap()),
+        ])
+        .unwrap();
+        assert_eq!(
+            list.pretty_print(),
+            "((tuple (a 1))\n  (tuple (a 2)))"
+        );
+        assert_eq!(
+            Value::okay(outer).unwrap().pretty_print(),
+            "(ok (tuple\n  (a 1)\n  (b (tuple\n    (c 2)\n    (d 3)))))"
+        );
+    }
+
     #[test]
     fn test_constructors() {
         assert_eq!(